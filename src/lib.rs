@@ -4,7 +4,10 @@
 mod web_asset_plugin;
 mod web_asset_source;
 
+pub use web_asset_plugin::WebAssetLoadFailed;
 pub use web_asset_plugin::WebAssetPlugin;
+pub use web_asset_source::BearerTokenFn;
+pub use web_asset_source::RetryPolicy;
 pub use web_asset_source::WebAssetReader;
 pub use web_asset_source::WebAssetReaderData;
 pub use web_asset_source::WebAssetReaderDataInner;
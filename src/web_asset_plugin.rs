@@ -2,6 +2,36 @@ use bevy::prelude::*;
 
 use crate::web_asset_source::*;
 use bevy::asset::io::AssetSource;
+use crossbeam_channel::{Receiver, Sender};
+
+/// Fired once a web asset has failed to load after its retries are exhausted.
+///
+/// Mirrors Bevy's [`AssetLoadFailedEvent`](bevy::asset::AssetLoadFailedEvent)
+/// but carries the raw url so games can show fallback art or a reconnect UI.
+#[derive(Event, Debug, Clone)]
+pub struct WebAssetLoadFailed {
+    /// The url that failed to load.
+    pub url: String,
+    /// The final HTTP status code, if a response was received.
+    pub status: Option<u16>,
+    /// A human-readable description of the final error.
+    pub error: String,
+    /// How many attempts were made in total (initial request plus retries).
+    pub attempts: u32,
+}
+
+/// Resource holding the receiving end of the failure channel that readers push
+/// to; drained each frame by [`forward_failures`].
+#[derive(Resource)]
+struct WebAssetFailures(Receiver<WebAssetLoadFailed>);
+
+/// Forward failures reported by the (async, world-less) readers into Bevy's
+/// event system.
+fn forward_failures(failures: Res<WebAssetFailures>, mut events: EventWriter<WebAssetLoadFailed>) {
+    while let Ok(failed) = failures.0.try_recv() {
+        events.send(failed);
+    }
+}
 
 /// Add this plugin to bevy to support loading http and https urls.
 ///
@@ -26,20 +56,59 @@ pub struct WebAssetPlugin {
     pub cache_resource: bool,
     /// Whether to reject meta requests.
     pub reject_meta_request: bool,
+    /// Extra headers attached to every outgoing request, e.g. for private
+    /// buckets or token-gated CDNs.
+    pub headers: Vec<(String, String)>,
+    /// Optional callback supplying an `Authorization: Bearer <token>` value,
+    /// re-evaluated before each request so expiring tokens keep working.
+    pub bearer_token: Option<BearerTokenFn>,
+    /// Stream response bodies lazily (using HTTP `Range` requests for seeks)
+    /// instead of buffering each asset up front. Only native builds stream; on
+    /// wasm this flag has no effect and every body is buffered whole (and
+    /// cached in OPFS as usual).
+    ///
+    /// Note: streamed bodies are never written to disk, so for assets served by
+    /// a range-capable server the native revalidating cache is not populated,
+    /// regardless of `cache_resource`. Assets whose server ignores ranges fall
+    /// back to buffering and are cached as usual.
+    pub streaming: bool,
+    /// How transient failures are retried before a load is given up on.
+    pub retry_policy: RetryPolicy,
 }
 
 impl Plugin for WebAssetPlugin {
     fn build(&self, app: &mut App) {
         let cache_resource = self.cache_resource;
         let reject_meta_request = self.reject_meta_request;
+        let streaming = self.streaming;
+        let headers = self.headers.clone();
+        let bearer_token = self.bearer_token.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        let (sender, receiver) = crossbeam_channel::unbounded::<WebAssetLoadFailed>();
+        app.add_event::<WebAssetLoadFailed>()
+            .insert_resource(WebAssetFailures(receiver))
+            .add_systems(Update, forward_failures);
+
         app.register_asset_source(
             "http",
-            AssetSource::build().with_reader(move || {
-                Box::new(WebAssetReader {
-                    cache_resource,
-                    reject_meta_request,
-                    connection: WebAssetReaderConnection::Http,
-                })
+            AssetSource::build().with_reader({
+                let headers = headers.clone();
+                let bearer_token = bearer_token.clone();
+                let retry_policy = retry_policy.clone();
+                let sender = sender.clone();
+                move || {
+                    Box::new(WebAssetReader {
+                        cache_resource,
+                        reject_meta_request,
+                        connection: WebAssetReaderConnection::Http,
+                        headers: headers.clone(),
+                        bearer_token: bearer_token.clone(),
+                        streaming,
+                        retry_policy: retry_policy.clone(),
+                        failure_sender: Some(sender.clone()),
+                    })
+                }
             }),
         );
         app.register_asset_source(
@@ -49,8 +118,16 @@ impl Plugin for WebAssetPlugin {
                     cache_resource,
                     reject_meta_request,
                     connection: WebAssetReaderConnection::Https,
+                    headers: headers.clone(),
+                    bearer_token: bearer_token.clone(),
+                    streaming,
+                    retry_policy: retry_policy.clone(),
+                    failure_sender: Some(sender.clone()),
                 })
             }),
         );
     }
 }
+
+/// Crate-private alias naming the channel type of [`WebAssetReader::failure_sender`].
+pub(crate) type FailureSender = Sender<WebAssetLoadFailed>;
@@ -1,8 +1,144 @@
 use bevy::{asset::io::PathStream, tasks::ConditionalSendFuture};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
 use bevy::asset::io::{AssetReader, AssetReaderError, Reader};
 
+use crate::web_asset_plugin::{FailureSender, WebAssetLoadFailed};
+
+/// Controls how transient failures (connection errors, `5xx`, `408`, `429`) are
+/// retried with exponential backoff before a load is given up on.
+///
+/// `404` (and any other non-retryable status) is never retried.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Base delay; the backoff for retry `n` is `base_delay * 2^(n - 1)` plus a
+    /// random jitter in `0..base_delay`.
+    pub base_delay: Duration,
+    /// Upper bound on the (pre-jitter) backoff delay.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The delay to wait before retry number `attempt` (1-based), honoring a
+    /// `Retry-After` hint from the server when present.
+    fn backoff(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let capped = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        capped + backoff_jitter(self.base_delay)
+    }
+}
+
+/// Whether an HTTP status code should trigger a retry.
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429) || (500..=599).contains(&status)
+}
+
+/// Parse a numeric `Retry-After` value (in seconds) into a [`Duration`].
+fn parse_retry_after(value: Option<&str>) -> Option<Duration> {
+    value
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// A small random jitter in `0..base`, derived from a per-arch entropy source.
+#[cfg(not(target_arch = "wasm32"))]
+fn backoff_jitter(base: Duration) -> Duration {
+    let base_nanos = base.as_nanos().max(1);
+    let entropy = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos() as u128)
+        .unwrap_or(0);
+    Duration::from_nanos((entropy % base_nanos) as u64)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn backoff_jitter(base: Duration) -> Duration {
+    Duration::from_secs_f64(js_sys::Math::random() * base.as_secs_f64())
+}
+
+/// Sleep asynchronously, busy-polling on the native single-threaded executor
+/// (matching `ContinuousPoll`) and using a `setTimeout` future on wasm.
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+    use std::time::Instant;
+
+    struct Delay(Instant);
+
+    impl Future for Delay {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if Instant::now() >= self.0 {
+                Poll::Ready(())
+            } else {
+                // Keep the single-threaded executor spinning until the deadline.
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    Delay(Instant::now() + duration).await
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(duration: Duration) {
+    use wasm_bindgen_futures::JsFuture;
+
+    let millis = duration.as_millis() as i32;
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        let _ = web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis);
+    });
+    let _ = JsFuture::from(promise).await;
+}
+
+/// Report an exhausted load to the Bevy event surface, if a channel is wired up.
+fn report_failure(
+    sender: &Option<FailureSender>,
+    url: &str,
+    status: Option<u16>,
+    error: &str,
+    attempts: u32,
+) {
+    if let Some(sender) = sender {
+        let _ = sender.send(WebAssetLoadFailed {
+            url: url.to_string(),
+            status,
+            error: error.to_string(),
+            attempts,
+        });
+    }
+}
+
+/// Callback returning a bearer token to attach to every request.
+///
+/// It is invoked once per request so that expiring credentials can be
+/// refreshed before each fetch; returning `None` omits the `Authorization`
+/// header for that request.
+pub type BearerTokenFn = Arc<dyn Fn() -> Option<String> + Send + Sync>;
+
 /// Treats paths as urls to load assets from.
 pub struct WebAssetReader {
     /// Option to cache resource.
@@ -12,6 +148,27 @@ pub struct WebAssetReader {
     pub reject_meta_request: bool,
     /// Connection type.
     pub connection: WebAssetReaderConnection,
+    /// Extra headers attached to every outgoing request, e.g. for private
+    /// buckets or token-gated CDNs.
+    pub headers: Vec<(String, String)>,
+    /// Optional callback supplying an `Authorization: Bearer <token>` value,
+    /// re-evaluated before each request so expiring tokens keep working.
+    pub bearer_token: Option<BearerTokenFn>,
+    /// Stream the response body lazily (using HTTP `Range` requests for seeks)
+    /// instead of buffering the whole asset up front. Only the native reader
+    /// streams; on wasm this flag has no effect and the body is buffered whole
+    /// (and cached in OPFS as usual).
+    ///
+    /// Note: a streamed body is never written to disk, so when `streaming` is on
+    /// and the server advertises `Accept-Ranges: bytes` the revalidating native
+    /// cache is not populated, regardless of `cache_resource`.
+    /// Assets that ignore ranges fall back to buffering and are cached as usual.
+    pub streaming: bool,
+    /// How transient failures are retried before a load is given up on.
+    pub retry_policy: RetryPolicy,
+    /// Channel used to surface exhausted loads as [`WebAssetLoadFailed`] events;
+    /// wired up by [`WebAssetPlugin`](crate::WebAssetPlugin).
+    pub(crate) failure_sender: Option<FailureSender>,
 }
 
 impl Default for WebAssetReader {
@@ -20,12 +177,34 @@ impl Default for WebAssetReader {
             cache_resource: false,
             reject_meta_request: false,
             connection: WebAssetReaderConnection::Https,
+            headers: Vec::new(),
+            bearer_token: None,
+            streaming: false,
+            retry_policy: RetryPolicy::default(),
+            failure_sender: None,
         }
     }
 }
 
+/// Resolve the full set of headers for a single outgoing request, combining the
+/// static `headers` with a freshly evaluated bearer token.
+///
+/// Called once per request (every retry attempt and every `RangeReader` range
+/// re-fetch) so that a token expiring mid-sequence is refreshed rather than the
+/// stale value being reused.
+fn resolve_headers(
+    headers: &[(String, String)],
+    bearer_token: &Option<BearerTokenFn>,
+) -> Vec<(String, String)> {
+    let mut headers = headers.to_vec();
+    if let Some(token) = bearer_token.as_ref().and_then(|token| token()) {
+        headers.push(("Authorization".to_string(), format!("Bearer {token}")));
+    }
+    headers
+}
+
 impl WebAssetReader {
-    #[cfg(feature = "cache_asset")]
+    #[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
     fn get_cache_path(&self, path: &Path) -> Option<PathBuf> {
         use slug::slugify;
 
@@ -51,12 +230,130 @@ impl WebAssetReader {
         None
     }
 
+    // The wasm cache is rooted at the Origin Private File System, so the layout
+    // matches native but drops the platform cache directory prefix.
+    #[cfg(all(feature = "cache_asset", target_arch = "wasm32"))]
+    fn get_cache_path(&self, path: &Path) -> Option<PathBuf> {
+        use slug::slugify;
+
+        if self.cache_resource {
+            let url_dir = path.parent().unwrap_or(path).to_string_lossy();
+            let url_filename = path
+                .file_name()
+                .map(|filename| filename.to_string_lossy())
+                .unwrap_or(std::borrow::Cow::Borrowed("filename"))
+                .to_string();
+
+            return Some(PathBuf::from(slugify(url_dir)).join(url_filename));
+        }
+        None
+    }
+
     #[cfg(not(feature = "cache_asset"))]
     fn get_cache_path(&self, _: &Path) -> Option<PathBuf> {
         None
     }
 }
 
+/// Read a cached body out of the Origin Private File System, if present.
+///
+/// Returns `None` (rather than erroring) whenever the `StorageManager` API is
+/// unavailable or the entry is missing, so callers can simply fall back to the
+/// network.
+#[cfg(all(feature = "cache_asset", target_arch = "wasm32"))]
+async fn opfs_read(cache_path: &Path) -> Option<Vec<u8>> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let (dir, file_name) = opfs_directory(cache_path, false).await?;
+    let handle = JsFuture::from(dir.get_file_handle(&file_name))
+        .await
+        .ok()?
+        .dyn_into::<web_sys::FileSystemFileHandle>()
+        .ok()?;
+    let file = JsFuture::from(handle.get_file())
+        .await
+        .ok()?
+        .dyn_into::<web_sys::File>()
+        .ok()?;
+    let buffer = JsFuture::from(file.array_buffer()).await.ok()?;
+    Some(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+/// Write a freshly fetched body back into the Origin Private File System.
+///
+/// Best-effort: any failure (including a missing `StorageManager`) is silently
+/// ignored, matching the native cache which never fails a load over a cache
+/// write error.
+#[cfg(all(feature = "cache_asset", target_arch = "wasm32"))]
+async fn opfs_write(cache_path: &Path, bytes: &[u8]) {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let Some((dir, file_name)) = opfs_directory(cache_path, true).await else {
+        return;
+    };
+
+    let options = web_sys::FileSystemGetFileOptions::new();
+    options.set_create(true);
+    let Ok(handle) = JsFuture::from(dir.get_file_handle_with_options(&file_name, &options)).await
+    else {
+        return;
+    };
+    let Ok(handle) = handle.dyn_into::<web_sys::FileSystemFileHandle>() else {
+        return;
+    };
+    let Ok(writable) = JsFuture::from(handle.create_writable()).await else {
+        return;
+    };
+    let Ok(writable) = writable.dyn_into::<web_sys::FileSystemWritableFileStream>() else {
+        return;
+    };
+    if let Ok(promise) = writable.write_with_u8_array(bytes) {
+        let _ = JsFuture::from(promise).await;
+    }
+    let _ = JsFuture::from(writable.close()).await;
+}
+
+/// Resolve the directory handle and file name for a cache path, walking (and
+/// optionally creating) any intermediate directories under the OPFS root.
+#[cfg(all(feature = "cache_asset", target_arch = "wasm32"))]
+async fn opfs_directory(
+    cache_path: &Path,
+    create: bool,
+) -> Option<(web_sys::FileSystemDirectoryHandle, String)> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let window = web_sys::window()?;
+    let storage = window.navigator().storage();
+    let mut dir = JsFuture::from(storage.get_directory())
+        .await
+        .ok()?
+        .dyn_into::<web_sys::FileSystemDirectoryHandle>()
+        .ok()?;
+
+    let file_name = cache_path.file_name()?.to_string_lossy().into_owned();
+
+    if let Some(parent) = cache_path.parent() {
+        for component in parent.components() {
+            let name = component.as_os_str().to_string_lossy();
+            if name.is_empty() {
+                continue;
+            }
+            let options = web_sys::FileSystemGetDirectoryOptions::new();
+            options.set_create(create);
+            dir = JsFuture::from(dir.get_directory_handle_with_options(&name, &options))
+                .await
+                .ok()?
+                .dyn_into::<web_sys::FileSystemDirectoryHandle>()
+                .ok()?;
+        }
+    }
+
+    Some((dir, file_name))
+}
+
 /// Treats paths as urls to load assets from.
 pub enum WebAssetReaderConnection {
     /// Unencrypted connections.
@@ -84,13 +381,37 @@ impl WebAssetReaderConnection {
     }
 }
 
+// Known limitation: streaming is not yet implemented on wasm. On `wasm32`
+// Bevy's `Reader`/`ConditionalSendFuture` drop their `Send` bound, so a reader
+// holding JS `fetch`/`ReadableStream` values would be legal here — the real gap
+// is that the `ReadableStream`-reader path simply hasn't been written. Until it
+// is, the `streaming` flag is accepted for API parity and the body is buffered
+// whole, same as the non-streaming path.
 #[cfg(target_arch = "wasm32")]
-async fn get(path: PathBuf, _: Option<PathBuf>) -> Result<Box<dyn Reader>, AssetReaderError> {
+#[cfg_attr(not(feature = "cache_asset"), allow(unused_variables))]
+#[allow(clippy::too_many_arguments)]
+async fn get(
+    path: PathBuf,
+    cache_path: Option<PathBuf>,
+    headers: Vec<(String, String)>,
+    bearer_token: Option<BearerTokenFn>,
+    _streaming: bool,
+    retry_policy: RetryPolicy,
+    failure_sender: Option<FailureSender>,
+) -> Result<Box<dyn Reader>, AssetReaderError> {
     use bevy::asset::io::VecReader;
     use js_sys::Uint8Array;
     use wasm_bindgen::JsCast;
     use wasm_bindgen_futures::JsFuture;
-    use web_sys::Response;
+    use web_sys::{Headers, Request, RequestInit, Response};
+
+    // Serve from the Origin Private File System cache when we have a hit.
+    #[cfg(feature = "cache_asset")]
+    if let Some(cache_path) = cache_path.as_ref() {
+        if let Some(bytes) = opfs_read(cache_path).await {
+            return Ok(Box::new(VecReader::new(bytes)));
+        }
+    }
 
     fn js_value_to_err<'a>(
         context: &'a str,
@@ -110,34 +431,385 @@ async fn get(path: PathBuf, _: Option<PathBuf>) -> Result<Box<dyn Reader>, Asset
     }
 
     let window = web_sys::window().unwrap();
-    let resp_value = JsFuture::from(window.fetch_with_str(path.to_str().unwrap()))
-        .await
-        .map_err(js_value_to_err("fetch path"))?;
-    let resp = resp_value
-        .dyn_into::<Response>()
-        .map_err(js_value_to_err("convert fetch to Response"))?;
+
+    // Retry transient failures with exponential backoff + jitter.
+    let mut attempt: u32 = 0;
+    let resp = loop {
+        // Re-evaluate the bearer token for every attempt so a credential that
+        // expires between retries is refreshed.
+        let request_headers = Headers::new().map_err(js_value_to_err("create headers"))?;
+        for (name, value) in resolve_headers(&headers, &bearer_token) {
+            request_headers
+                .append(&name, &value)
+                .map_err(js_value_to_err("append header"))?;
+        }
+        let init = RequestInit::new();
+        init.set_headers(&request_headers);
+
+        let request = Request::new_with_str_and_init(path.to_str().unwrap(), &init)
+            .map_err(js_value_to_err("create request"))?;
+
+        match JsFuture::from(window.fetch_with_request(&request)).await {
+            Ok(resp_value) => {
+                let resp = resp_value
+                    .dyn_into::<Response>()
+                    .map_err(js_value_to_err("convert fetch to Response"))?;
+                if is_retryable_status(resp.status()) && attempt < retry_policy.max_retries {
+                    let retry_after =
+                        parse_retry_after(resp.headers().get("retry-after").ok().flatten().as_deref());
+                    attempt += 1;
+                    sleep(retry_policy.backoff(attempt, retry_after)).await;
+                    continue;
+                }
+                break resp;
+            }
+            Err(value) => {
+                if attempt < retry_policy.max_retries {
+                    attempt += 1;
+                    sleep(retry_policy.backoff(attempt, None)).await;
+                    continue;
+                }
+                let err = js_value_to_err("fetch path")(value);
+                report_failure(
+                    &failure_sender,
+                    path.to_str().unwrap_or_default(),
+                    None,
+                    &err.to_string(),
+                    attempt + 1,
+                );
+                return Err(AssetReaderError::Io(err.into()));
+            }
+        }
+    };
+
     match resp.status() {
         200 => {
             let data = JsFuture::from(resp.array_buffer().unwrap()).await.unwrap();
             let bytes = Uint8Array::new(&data).to_vec();
+
+            // Write the freshly fetched body back into the OPFS cache.
+            #[cfg(feature = "cache_asset")]
+            if let Some(cache_path) = cache_path.as_ref() {
+                opfs_write(cache_path, &bytes).await;
+            }
+
             let reader: Box<dyn Reader> = Box::new(VecReader::new(bytes));
             Ok(reader)
         }
         404 => Err(AssetReaderError::NotFound(path)),
-        status => Err(AssetReaderError::Io(
-            std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Encountered unexpected HTTP status {status}"),
-            )
-            .into(),
-        )),
+        status => {
+            let message = format!("Encountered unexpected HTTP status {status}");
+            report_failure(
+                &failure_sender,
+                path.to_str().unwrap_or_default(),
+                Some(status),
+                &message,
+                attempt + 1,
+            );
+            Err(AssetReaderError::Io(
+                std::io::Error::new(std::io::ErrorKind::Other, message).into(),
+            ))
+        }
+    }
+}
+
+/// Sidecar metadata persisted next to a cached asset body so the cache can be
+/// revalidated against the origin instead of being trusted forever.
+///
+/// Stored as `<file>.httpcache` JSON, mirroring the validator-based download
+/// caches used by tools like Deno.
+#[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct HttpCacheEntry {
+    /// The `ETag` response header, used for `If-None-Match` revalidation.
+    etag: Option<String>,
+    /// The `Last-Modified` response header, used for `If-Modified-Since`.
+    last_modified: Option<String>,
+    /// The `max-age` directive parsed from `Cache-Control`, in seconds.
+    max_age: Option<u64>,
+    /// Whether `Cache-Control` contained `no-store`.
+    no_store: bool,
+    /// Seconds since the unix epoch when the body was last fetched/revalidated.
+    fetched_at: u64,
+}
+
+#[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
+impl HttpCacheEntry {
+    /// Build an entry from a fresh `200 OK` response.
+    fn from_response(response: &surf::Response, fetched_at: u64) -> Self {
+        let header = |name: &str| {
+            response
+                .header(name)
+                .map(|values| values.as_str().to_owned())
+        };
+        let (max_age, no_store) = response
+            .header("cache-control")
+            .map(|values| parse_cache_control(values.as_str()))
+            .unwrap_or((None, false));
+        Self {
+            etag: header("etag"),
+            last_modified: header("last-modified"),
+            max_age,
+            no_store,
+            fetched_at,
+        }
+    }
+
+    /// Whether the cached body is still within its `max-age` freshness window.
+    fn is_fresh(&self, now: u64) -> bool {
+        match self.max_age {
+            Some(max_age) => now.saturating_sub(self.fetched_at) < max_age,
+            None => false,
+        }
     }
 }
 
+/// The path of the `.httpcache` sidecar for a cached body.
+#[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
+fn sidecar_path(cache_path: &Path) -> PathBuf {
+    let mut file_name = cache_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".httpcache");
+    cache_path.with_file_name(file_name)
+}
+
+/// Parse the `max-age` and `no-store` directives out of a `Cache-Control` value.
+#[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
+fn parse_cache_control(value: &str) -> (Option<u64>, bool) {
+    let mut max_age = None;
+    let mut no_store = false;
+    for directive in value.split(',') {
+        let directive = directive.trim().to_ascii_lowercase();
+        if directive == "no-store" {
+            no_store = true;
+        } else if let Some(seconds) = directive.strip_prefix("max-age=") {
+            max_age = seconds.trim().parse().ok();
+        }
+    }
+    (max_age, no_store)
+}
+
+/// Seconds since the unix epoch, clamped to zero if the clock is before it.
+#[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// Parse the starting byte offset out of a `Content-Range: bytes <start>-<end>/<total>`
+/// header value, ignoring unsatisfiable (`*`) or malformed forms.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_content_range_start(value: &str) -> Option<u64> {
+    let range = value.trim().strip_prefix("bytes")?.trim_start();
+    let start = range.split('-').next()?.trim();
+    start.parse().ok()
+}
+
+/// A [`Reader`] that streams a response body lazily and satisfies seeks with
+/// HTTP `Range` requests, so large assets never have to be buffered whole.
+///
+/// Only used when the server advertises `Accept-Ranges: bytes`; callers fall
+/// back to whole-file buffering otherwise.
+#[cfg(not(target_arch = "wasm32"))]
+struct RangeReader {
+    client: surf::Client,
+    url: String,
+    headers: Vec<(String, String)>,
+    /// Re-evaluated before every range re-fetch so expiring tokens keep working
+    /// over a long streaming load.
+    bearer_token: Option<BearerTokenFn>,
+    /// Total length when known from `Content-Length`, needed for `SeekFrom::End`.
+    len: Option<u64>,
+    /// Current read cursor into the asset.
+    pos: u64,
+    /// Bytes the open response sent ahead of `pos` that must be discarded before
+    /// serving data — non-zero only when the server ignored our `Range` header
+    /// and replied `200` (or a `206` starting before `pos`).
+    skip: u64,
+    state: RangeState,
+}
+
 #[cfg(not(target_arch = "wasm32"))]
+enum RangeState {
+    /// No open stream; the next read re-fetches starting at `pos`.
+    Idle,
+    /// A ranged GET is in flight; resolves to the open response plus the number
+    /// of leading bytes to discard before the body aligns with `pos`.
+    Fetching(
+        std::pin::Pin<
+            Box<dyn std::future::Future<Output = std::io::Result<(surf::Response, u64)>> + Send>,
+        >,
+    ),
+    /// Streaming the body of an open response.
+    Reading(surf::Response),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RangeReader {
+    /// Build a future that issues `GET` with `Range: bytes=<pos>-` and yields
+    /// the response once the status line has arrived.
+    fn fetch(&self) -> RangeState {
+        let client = self.client.clone();
+        let url = self.url.clone();
+        // Re-evaluate the bearer token for every range re-fetch.
+        let headers = resolve_headers(&self.headers, &self.bearer_token);
+        let pos = self.pos;
+        RangeState::Fetching(Box::pin(async move {
+            use surf::StatusCode;
+
+            let mut request = client.get(&url);
+            for (name, value) in &headers {
+                request = request.header(name.as_str(), value.as_str());
+            }
+            request = request.header("Range", format!("bytes={pos}-").as_str());
+            let response = request
+                .await
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err.to_string()))?;
+            match response.status() {
+                // A proper ranged reply: verify where its bytes actually start.
+                StatusCode::PartialContent => {
+                    let start = response
+                        .header("content-range")
+                        .and_then(|values| parse_content_range_start(values.as_str()));
+                    match start {
+                        // Starts exactly where we asked: serve directly.
+                        Some(start) if start == pos => Ok((response, 0)),
+                        // Starts before our cursor: discard the overlap.
+                        Some(start) if start < pos => Ok((response, pos - start)),
+                        // Starts after our cursor: we can never reach `pos`.
+                        Some(start) => Err(std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            format!(
+                                "server returned range starting at {start}, past requested {pos}, for {url}"
+                            ),
+                        )),
+                        // No/garbled `Content-Range`: assume it honored the start.
+                        None => Ok((response, 0)),
+                    }
+                }
+                // The server ignored `Range` and sent the whole body from offset
+                // zero; skip forward to the seek position instead of serving
+                // byte zero as if it were `pos`.
+                StatusCode::Ok => Ok((response, pos)),
+                code => Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("unexpected status code {code} while streaming {url}"),
+                )),
+            }
+        }))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl bevy::asset::io::AsyncRead for RangeReader {
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        use std::task::Poll;
+
+        let this = self.get_mut();
+        loop {
+            // Unlike the blocking initial `get` (which busy-polls to drive the
+            // single-threaded executor), this reader runs on Bevy's normal async
+            // executor: the loop only re-polls on synchronous state transitions,
+            // and a network-bound inner poll returns `Pending` after registering
+            // `cx`'s waker, so we never busy-spin a worker thread.
+            match &mut this.state {
+                RangeState::Idle => this.state = this.fetch(),
+                RangeState::Fetching(future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok((response, skip))) => {
+                        this.skip = skip;
+                        this.state = RangeState::Reading(response);
+                    }
+                    Poll::Ready(Err(err)) => {
+                        this.state = RangeState::Idle;
+                        return Poll::Ready(Err(err));
+                    }
+                },
+                RangeState::Reading(response) => {
+                    // Drain any bytes the server sent ahead of our seek position
+                    // (a `200` or early-starting `206`) into a scratch buffer
+                    // before handing real data to the caller.
+                    if this.skip > 0 {
+                        let mut scratch = [0u8; 8192];
+                        let want = this.skip.min(scratch.len() as u64) as usize;
+                        match std::pin::Pin::new(response).poll_read(cx, &mut scratch[..want]) {
+                            Poll::Pending => return Poll::Pending,
+                            Poll::Ready(Ok(0)) => {
+                                return Poll::Ready(Err(std::io::Error::new(
+                                    std::io::ErrorKind::UnexpectedEof,
+                                    "stream ended before reaching the seek position",
+                                )))
+                            }
+                            Poll::Ready(Ok(read)) => {
+                                this.skip -= read as u64;
+                                continue;
+                            }
+                            Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                        }
+                    }
+                    return match std::pin::Pin::new(response).poll_read(cx, buf) {
+                        Poll::Pending => Poll::Pending,
+                        Poll::Ready(Ok(read)) => {
+                            this.pos += read as u64;
+                            Poll::Ready(Ok(read))
+                        }
+                        Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl bevy::asset::io::AsyncSeek for RangeReader {
+    fn poll_seek(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+        pos: std::io::SeekFrom,
+    ) -> std::task::Poll<std::io::Result<u64>> {
+        use std::io::{Error, ErrorKind, SeekFrom};
+        use std::task::Poll;
+
+        let this = self.get_mut();
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => offset,
+            SeekFrom::Current(offset) => this.pos.saturating_add_signed(offset),
+            SeekFrom::End(offset) => match this.len {
+                Some(len) => len.saturating_add_signed(offset),
+                None => {
+                    return Poll::Ready(Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "cannot seek from end: content length unknown",
+                    )))
+                }
+            },
+        };
+        if new_pos != this.pos {
+            this.pos = new_pos;
+            // Drop the current stream; the next read re-fetches from `pos`.
+            this.state = RangeState::Idle;
+        }
+        Poll::Ready(Ok(new_pos))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg_attr(not(feature = "cache_asset"), allow(unused_variables))]
+#[allow(clippy::too_many_arguments)]
 async fn get(
     path: PathBuf,
     cache_path: Option<PathBuf>,
+    headers: Vec<(String, String)>,
+    bearer_token: Option<BearerTokenFn>,
+    streaming: bool,
+    retry_policy: RetryPolicy,
+    failure_sender: Option<FailureSender>,
 ) -> Result<Box<dyn Reader>, AssetReaderError> {
     use std::fs;
     use std::future::Future;
@@ -145,17 +817,30 @@ async fn get(
     use std::pin::Pin;
     use std::task::{Context, Poll};
 
+    use bevy::asset::io::VecReader;
+    use surf::StatusCode;
+
+    // If we have a fresh cached body serve it outright; if it's stale but has
+    // validators, remember them so we can issue a conditional GET below.
+    #[cfg(feature = "cache_asset")]
+    let mut revalidate: Option<HttpCacheEntry> = None;
+    #[cfg(feature = "cache_asset")]
     if let Some(cache_path) = cache_path.as_ref() {
         if cache_path.exists() {
-            // TODO: fallback to deleting cache if it fails to read, and re-download the file?
-            // Currently user can delete the cache manually to trigger a re-download.
-            return Ok(Box::new(VecReader::new(fs::read(cache_path)?)));
+            match fs::read(sidecar_path(cache_path))
+                .ok()
+                .and_then(|bytes| serde_json::from_slice::<HttpCacheEntry>(&bytes).ok())
+            {
+                Some(entry) if entry.is_fresh(now_secs()) => {
+                    return Ok(Box::new(VecReader::new(fs::read(cache_path)?)));
+                }
+                Some(entry) => revalidate = Some(entry),
+                // Legacy cache written before sidecars existed: serve it as-is.
+                None => return Ok(Box::new(VecReader::new(fs::read(cache_path)?))),
+            }
         }
     }
 
-    use bevy::asset::io::VecReader;
-    use surf::StatusCode;
-
     #[pin_project::pin_project]
     struct ContinuousPoll<T>(#[pin] T);
 
@@ -186,57 +871,164 @@ async fn get(
     #[cfg(feature = "redirect")]
     let client = surf::Client::new().with(surf::middleware::Redirect::default());
 
-    let mut response = ContinuousPoll(client.get(str_path)).await.map_err(|err| {
-        AssetReaderError::Io(
-            io::Error::new(
-                io::ErrorKind::Other,
-                format!(
+    // Retry transient failures with exponential backoff + jitter, re-issuing a
+    // fresh request each attempt (a `RequestBuilder` is single-use).
+    let mut attempt: u32 = 0;
+    let mut response = loop {
+        let mut request = client.get(str_path);
+        // Re-evaluate the bearer token for every attempt so a credential that
+        // expires between retries is refreshed.
+        for (name, value) in resolve_headers(&headers, &bearer_token) {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        #[cfg(feature = "cache_asset")]
+        if let Some(entry) = revalidate.as_ref() {
+            if let Some(etag) = entry.etag.as_ref() {
+                request = request.header("If-None-Match", etag.as_str());
+            }
+            if let Some(last_modified) = entry.last_modified.as_ref() {
+                request = request.header("If-Modified-Since", last_modified.as_str());
+            }
+        }
+
+        match ContinuousPoll(request).await {
+            Ok(response) => {
+                let status = response.status();
+                if is_retryable_status(status.into()) && attempt < retry_policy.max_retries {
+                    let retry_after = parse_retry_after(
+                        response.header("retry-after").map(|values| values.as_str()),
+                    );
+                    attempt += 1;
+                    sleep(retry_policy.backoff(attempt, retry_after)).await;
+                    continue;
+                }
+                break response;
+            }
+            Err(err) => {
+                if attempt < retry_policy.max_retries {
+                    attempt += 1;
+                    sleep(retry_policy.backoff(attempt, None)).await;
+                    continue;
+                }
+                let message = format!(
                     "unexpected status code {} while loading {}: {}",
                     err.status(),
                     path.display(),
                     err.into_inner(),
-                ),
-            )
-            .into(),
-        )
-    })?;
+                );
+                report_failure(&failure_sender, str_path, None, &message, attempt + 1);
+                return Err(AssetReaderError::Io(
+                    io::Error::new(io::ErrorKind::Other, message).into(),
+                ));
+            }
+        }
+    };
 
     match response.status() {
         StatusCode::Ok => {
+            // Stream lazily when requested and the server supports ranges;
+            // otherwise fall through to buffering the whole body.
+            if streaming {
+                let accept_ranges = response
+                    .header("accept-ranges")
+                    .map(|values| values.as_str().to_ascii_lowercase().contains("bytes"))
+                    .unwrap_or(false);
+                if accept_ranges {
+                    let len = response
+                        .header("content-length")
+                        .and_then(|values| values.as_str().parse().ok());
+                    return Ok(Box::new(RangeReader {
+                        client,
+                        url: str_path.to_owned(),
+                        headers,
+                        bearer_token,
+                        len,
+                        pos: 0,
+                        skip: 0,
+                        state: RangeState::Reading(response),
+                    }));
+                }
+            }
+
+            #[cfg(feature = "cache_asset")]
+            let entry = HttpCacheEntry::from_response(&response, now_secs());
+
             let buf = ContinuousPoll(response.body_bytes())
                 .await
                 .map_err(|_| AssetReaderError::NotFound(path.to_path_buf()))?;
 
             #[cfg(feature = "cache_asset")]
             if let Some(cache_path) = cache_path {
-                use std::io::Write;
+                // `no-store` means the response must never touch disk. Also evict
+                // any previously-cached body + sidecar so a later revalidation
+                // can't keep serving the now-forbidden stale copy.
+                if entry.no_store {
+                    let _ = fs::remove_file(&cache_path);
+                    let _ = fs::remove_file(sidecar_path(&cache_path));
+                } else {
+                    use std::io::Write;
 
-                if let Some(parent_dirs) = cache_path.parent() {
-                    fs::create_dir_all(parent_dirs)?;
+                    if let Some(parent_dirs) = cache_path.parent() {
+                        fs::create_dir_all(parent_dirs)?;
+                    }
+                    let mut file = fs::OpenOptions::new()
+                        .create(true)
+                        .truncate(true)
+                        .write(true)
+                        .open(&cache_path)?;
+                    // write result to disk, refresh the sidecar, then return the
+                    // result as a file reader
+                    file.write_all(buf.as_slice())?;
+                    if let Ok(serialized) = serde_json::to_vec(&entry) {
+                        let _ = fs::write(sidecar_path(&cache_path), serialized);
+                    }
+                    return Ok(Box::new(VecReader::new(fs::read(&cache_path)?)));
                 }
-                let mut file = fs::OpenOptions::new()
-                    .create(true)
-                    .truncate(true)
-                    .write(true)
-                    .open(&cache_path)?;
-                // write result to disk, then return the result as a file reader
-                file.write_all(buf.as_slice())?;
-                return Ok(Box::new(VecReader::new(fs::read(&cache_path)?)));
             }
             Ok(Box::new(VecReader::new(buf)) as _)
         }
+        StatusCode::NotModified => {
+            // The cached body is still valid; refresh its freshness window and
+            // serve it without re-downloading.
+            #[cfg(feature = "cache_asset")]
+            if let Some(cache_path) = cache_path.as_ref() {
+                if let Some(mut entry) = revalidate {
+                    entry.fetched_at = now_secs();
+                    if let Ok(serialized) = serde_json::to_vec(&entry) {
+                        let _ = fs::write(sidecar_path(cache_path), serialized);
+                    }
+                }
+                return Ok(Box::new(VecReader::new(fs::read(cache_path)?)));
+            }
+            Err(AssetReaderError::Io(
+                io::Error::new(
+                    io::ErrorKind::Other,
+                    format!(
+                        "unexpected 304 Not Modified while loading {}",
+                        path.display()
+                    ),
+                )
+                .into(),
+            ))
+        }
         StatusCode::NotFound => Err(AssetReaderError::NotFound(path)),
-        code => Err(AssetReaderError::Io(
-            io::Error::new(
-                io::ErrorKind::Other,
-                format!(
-                    "unexpected status code {} while loading {}",
-                    code,
-                    path.display()
-                ),
-            )
-            .into(),
-        )),
+        code => {
+            let message = format!(
+                "unexpected status code {} while loading {}",
+                code,
+                path.display()
+            );
+            report_failure(
+                &failure_sender,
+                str_path,
+                Some(code.into()),
+                &message,
+                attempt + 1,
+            );
+            Err(AssetReaderError::Io(
+                io::Error::new(io::ErrorKind::Other, message).into(),
+            ))
+        }
     }
 }
 
@@ -248,7 +1040,15 @@ impl AssetReader for WebAssetReader {
         let uri = self.connection.make_uri(path);
 
         let cache_path = self.get_cache_path(&uri);
-        get(uri, cache_path)
+        get(
+            uri,
+            cache_path,
+            self.headers.clone(),
+            self.bearer_token.clone(),
+            self.streaming,
+            self.retry_policy.clone(),
+            self.failure_sender.clone(),
+        )
     }
 
     async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<Box<dyn Reader>, AssetReaderError> {
@@ -261,7 +1061,18 @@ impl AssetReader for WebAssetReader {
         match self.connection.make_meta_uri(path) {
             Some(uri) => {
                 let cache_path = self.get_cache_path(&uri);
-                match get(uri, cache_path).await {
+                // Meta files are tiny, so never stream them.
+                match get(
+                    uri,
+                    cache_path,
+                    self.headers.clone(),
+                    self.bearer_token.clone(),
+                    false,
+                    self.retry_policy.clone(),
+                    self.failure_sender.clone(),
+                )
+                .await
+                {
                     Ok(reader) => Ok(reader),
                     Err(err) => Err(AssetReaderError::NotFound(
                         format!("Error loading meta: {err}").into(),
@@ -344,4 +1155,83 @@ mod tests {
             None
         );
     }
+
+    #[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
+    #[test]
+    fn parse_cache_control_directives() {
+        assert_eq!(parse_cache_control("max-age=3600"), (Some(3600), false));
+        assert_eq!(
+            parse_cache_control("public, max-age=60, no-store"),
+            (Some(60), true)
+        );
+        assert_eq!(parse_cache_control("No-Store"), (None, true));
+        assert_eq!(parse_cache_control("private"), (None, false));
+    }
+
+    #[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
+    #[test]
+    fn cache_entry_freshness() {
+        let entry = HttpCacheEntry {
+            max_age: Some(100),
+            fetched_at: 1_000,
+            ..Default::default()
+        };
+        assert!(entry.is_fresh(1_050));
+        assert!(!entry.is_fresh(1_100));
+        assert!(!HttpCacheEntry::default().is_fresh(0));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn parse_content_range_offsets() {
+        assert_eq!(parse_content_range_start("bytes 200-1023/2048"), Some(200));
+        assert_eq!(parse_content_range_start("bytes 0-0/1"), Some(0));
+        assert_eq!(parse_content_range_start("bytes */2048"), None);
+        assert_eq!(parse_content_range_start("garbage"), None);
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        assert!(is_retryable_status(500));
+        assert!(is_retryable_status(503));
+        assert!(is_retryable_status(408));
+        assert!(is_retryable_status(429));
+        assert!(!is_retryable_status(404));
+        assert!(!is_retryable_status(200));
+    }
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+        // Exponential part (pre-jitter) for attempt 1 is one base delay.
+        assert!(policy.backoff(1, None) >= Duration::from_millis(100));
+        // ... and never exceeds the cap plus a single base delay of jitter.
+        assert!(policy.backoff(5, None) <= Duration::from_millis(600));
+        // A Retry-After hint takes precedence, clamped to the cap.
+        assert_eq!(
+            policy.backoff(1, Some(Duration::from_secs(30))),
+            Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_seconds() {
+        assert_eq!(parse_retry_after(Some("120")), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(Some("  5 ")), Some(Duration::from_secs(5)));
+        assert_eq!(parse_retry_after(Some("Wed, 21 Oct 2015 07:28:00 GMT")), None);
+        assert_eq!(parse_retry_after(None), None);
+    }
+
+    #[cfg(all(feature = "cache_asset", not(target_arch = "wasm32")))]
+    #[test]
+    fn sidecar_path_appends_suffix() {
+        assert_eq!(
+            sidecar_path(Path::new("/cache/dump/favicon.png")),
+            PathBuf::from("/cache/dump/favicon.png.httpcache")
+        );
+    }
 }